@@ -0,0 +1,805 @@
+use anyhow::{anyhow, bail, Context, Result};
+use atty::Stream;
+use clap::{Parser, Subcommand};
+use glob::Pattern as GlobPattern;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command as ProcessCommand, Stdio};
+use walkdir::WalkDir;
+
+/// Represents a saved pattern with optional flags, patterns, and engine.
+#[derive(Serialize, Deserialize)]
+pub struct Pattern {
+    pub flags: Option<String>,
+    pub pattern: Option<String>,
+    pub patterns: Option<Vec<String>>,
+    pub engine: Option<String>,
+    /// Glob(s) of files to search; walked and matched instead of expanded up front.
+    pub include: Option<Vec<String>>,
+    /// Glob(s) of files to skip while walking `include`'s base directories.
+    pub exclude: Option<Vec<String>>,
+    /// The regex dialect the pattern was authored in: `bre`, `ere`, or `pcre`.
+    pub syntax: Option<String>,
+    /// Other saved pattern names whose alternatives are folded into this one.
+    pub includes: Option<Vec<String>>,
+}
+
+/// Command-line interface definition using clap.
+#[derive(Parser)]
+#[command(
+    name = "gf",
+    about = "Pattern manager for grep-like tools",
+    version = "1.0.0"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+/// Each gf operation as its own subcommand, with named, validated fields.
+#[derive(Subcommand)]
+enum Commands {
+    /// Save a pattern (e.g., gf save pat-name 'search-pattern' --flags -Hnri)
+    Save {
+        /// Name to save the pattern under
+        name: String,
+        /// A single pattern to save (conflicts with --patterns)
+        pattern: Option<String>,
+        /// Flags to pass to the engine (e.g. -Hnri)
+        #[arg(long, allow_hyphen_values = true)]
+        flags: Option<String>,
+        /// Multiple alternative patterns, unioned into (a|b|c)
+        #[arg(long, conflicts_with = "pattern")]
+        patterns: Option<Vec<String>>,
+        /// Engine to use (e.g. grep, rg, ag)
+        #[arg(long)]
+        engine: Option<String>,
+    },
+    /// List available patterns
+    List,
+    /// Open a saved pattern in $VISUAL/$EDITOR, creating it if needed
+    Edit {
+        /// Name of the pattern to edit
+        name: String,
+    },
+    /// Print the command rather than executing it
+    Dump {
+        /// Name of the pattern to dump
+        name: String,
+        /// Files or directory to search (defaults to ".")
+        files: Option<String>,
+    },
+    /// Run a saved pattern (this is also the default when no subcommand matches)
+    Run {
+        /// Name of the pattern to run
+        name: String,
+        /// Additional arguments; the first is the files or directory to search
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Falls back to `run` so `gf <name> [files]` works without the keyword
+    #[command(external_subcommand)]
+    External(Vec<String>),
+}
+
+/// Runs gf with explicit arguments (the first of which is the program name,
+/// matching `std::env::args()`), returning the process's intended exit code
+/// instead of calling `std::process::exit`. This lets other tools embed gf
+/// and lets tests exercise command construction without spawning the binary.
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<i32> {
+    let cli = match Cli::try_parse_from(args) {
+        Ok(cli) => cli,
+        Err(err) => {
+            // Usage errors, --help and --version print and return their code.
+            err.print()?;
+            return Ok(err.exit_code());
+        }
+    };
+
+    match cli.command {
+        Commands::List => {
+            let patterns = list_patterns().context("Failed to list patterns")?;
+            for pat in patterns {
+                println!("{}", pat);
+            }
+            Ok(0)
+        }
+        Commands::Save {
+            name,
+            pattern,
+            flags,
+            patterns,
+            engine,
+        } => {
+            save_pattern(
+                &name,
+                flags.as_deref(),
+                pattern.as_deref(),
+                patterns,
+                engine,
+            )?;
+            Ok(0)
+        }
+        Commands::Edit { name } => {
+            edit_pattern(&name)?;
+            Ok(0)
+        }
+        Commands::Dump { name, files } => {
+            let files = files.as_deref().unwrap_or(".");
+            let prepared = prepare_pattern(&name, files)?;
+
+            let mut command = format!("{} ", prepared.operator);
+
+            if prepared.needs_pcre {
+                command.push_str("-P ");
+            }
+
+            if let Some(flags) = &prepared.flags {
+                command.push_str(flags);
+                command.push(' ');
+            }
+
+            command.push_str(&format!(
+                "{:?} {}",
+                prepared.pattern_str,
+                prepared.file_args.join(" ")
+            ));
+
+            println!("{}", command);
+            Ok(0)
+        }
+        Commands::Run { name, args } => run_pattern(&name, &args),
+        Commands::External(args) => {
+            let name = args.first().context("Pattern name is required")?;
+            run_pattern(name, &args[1..])
+        }
+    }
+}
+
+/// Executes a saved pattern against `args` (the first of which, if present,
+/// is the files or directory to search) and returns its exit code.
+fn run_pattern(name: &str, args: &[String]) -> Result<i32> {
+    let files = args.first().map(|s| s.as_str()).unwrap_or(".");
+    let prepared = prepare_pattern(name, files)?;
+
+    let stdin_is_pipe = stdin_is_pipe();
+
+    let mut cmd = build_command(
+        &prepared.operator,
+        prepared.flags.as_deref(),
+        prepared.needs_pcre,
+        &prepared.pattern_str,
+        &prepared.file_args,
+        !stdin_is_pipe,
+    );
+
+    cmd.stdin(Stdio::inherit());
+    cmd.stdout(Stdio::inherit());
+    cmd.stderr(Stdio::inherit());
+
+    let status = cmd.status().context("Failed to execute command")?;
+
+    Ok(status.code().unwrap_or(1))
+}
+
+/// A saved pattern fully resolved and translated for its engine: the
+/// concrete file list, the alternation string in the engine's dialect,
+/// and whatever flags (including an added `-P`) the engine needs.
+struct PreparedPattern {
+    operator: String,
+    flags: Option<String>,
+    needs_pcre: bool,
+    pattern_str: String,
+    file_args: Vec<String>,
+}
+
+/// Loads `name`, resolves its `includes` chain, builds its file argument
+/// list, and translates its pattern into the dialect its engine expects.
+fn prepare_pattern(name: &str, files: &str) -> Result<PreparedPattern> {
+    let pattern_dir = get_pattern_dir().context("Unable to open user's pattern directory")?;
+    let filename = pattern_dir.join(format!("{}.json", name));
+
+    let f = fs::File::open(&filename).with_context(|| format!("No such pattern '{}'", name))?;
+
+    let pat: Pattern = serde_json::from_reader(f)
+        .with_context(|| format!("Pattern file '{}' is malformed", filename.display()))?;
+
+    let file_args = build_file_args(&pat, files)?;
+
+    let mut in_progress = Vec::new();
+    let resolved = resolve_pattern(&pattern_dir, name, &mut in_progress)?;
+
+    let operator = resolved.engine.unwrap_or_else(|| "grep".to_string());
+
+    let from_syntax = resolved.syntax.unwrap_or_else(|| "bre".to_string());
+    let (pattern_str, needs_pcre) =
+        translate_pattern(&resolved.alternatives, &from_syntax, &operator)
+        .with_context(|| {
+            format!(
+                "Pattern file '{}' has an invalid syntax",
+                filename.display()
+            )
+        })?;
+
+    Ok(PreparedPattern {
+        operator,
+        flags: resolved.flags,
+        needs_pcre,
+        pattern_str,
+        file_args,
+    })
+}
+
+/// Builds the `ProcessCommand` that runs `pattern_str` through `operator`,
+/// applying `-P` when the translated pattern needs PCRE support, the saved
+/// `flags`, and the resolved file arguments (when `include_files` is set).
+pub fn build_command(
+    operator: &str,
+    flags: Option<&str>,
+    needs_pcre: bool,
+    pattern_str: &str,
+    file_args: &[String],
+    include_files: bool,
+) -> ProcessCommand {
+    let mut cmd = ProcessCommand::new(operator);
+
+    if needs_pcre {
+        cmd.arg("-P");
+    }
+
+    if let Some(flags) = flags {
+        cmd.args(flags.split_whitespace());
+    }
+
+    cmd.arg(pattern_str);
+
+    if include_files {
+        cmd.args(file_args);
+    }
+
+    cmd
+}
+
+/// Builds the concrete list of file arguments to pass to the engine.
+///
+/// When `pat.include` is unset this falls back to the old behaviour of
+/// passing `files` through untouched. Otherwise each include entry is
+/// split into a concrete base directory plus its trailing glob, and only
+/// those bases are walked, testing every visited entry against the
+/// compiled exclude patterns. This avoids globbing (and excluding from)
+/// the whole tree up front. An include with no glob suffix (e.g. `"src"`)
+/// names a base to recurse into wholesale rather than a pattern to match
+/// against it, so every file `WalkDir` turns up under it is kept as-is.
+/// Since a silently empty file list would otherwise make the engine block
+/// on the real terminal stdin, a fully-resolved include list that still
+/// yields no files is treated as a configuration error.
+fn build_file_args(pat: &Pattern, files: &str) -> Result<Vec<String>> {
+    let includes = match &pat.include {
+        Some(includes) if !includes.is_empty() => includes,
+        _ => return Ok(vec![files.to_string()]),
+    };
+
+    let invocation_dir = env::current_dir().context("Failed to determine current directory")?;
+
+    let exclude_patterns = pat
+        .exclude
+        .as_deref()
+        .unwrap_or(&[])
+        .iter()
+        .map(|entry| compile_glob(&make_absolute(entry, &invocation_dir)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut out = Vec::new();
+
+    for entry in includes {
+        let absolute = make_absolute(entry, &invocation_dir);
+
+        if is_remote_entry(&absolute) {
+            out.push(absolute);
+            continue;
+        }
+
+        let (base, glob_pattern) = split_glob_base(&absolute);
+        let matcher = glob_pattern.map(|p| compile_glob(&p)).transpose()?;
+
+        for walked in WalkDir::new(&base)
+            .into_iter()
+            .filter_entry(|e| !matches_any(e.path(), &exclude_patterns))
+        {
+            let walked = match walked {
+                Ok(walked) => walked,
+                // A missing base contributes no files rather than being a hard
+                // error; the empty-result check below reports that clearly.
+                Err(e) if e.io_error().map(|io| io.kind()) == Some(std::io::ErrorKind::NotFound) => {
+                    continue
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to walk '{}'", base.display()))
+                }
+            };
+
+            if !walked.file_type().is_file() {
+                continue;
+            }
+
+            let path = walked.path();
+
+            if matches_any(path, &exclude_patterns) {
+                continue;
+            }
+
+            match &matcher {
+                Some(matcher) if !matcher.matches_path(path) => continue,
+                _ => out.push(path.display().to_string()),
+            }
+        }
+    }
+
+    if out.is_empty() {
+        bail!("Pattern's 'include' entries resolved to zero files");
+    }
+
+    Ok(out)
+}
+
+/// Returns true if `entry` is a URL-like reference that should not be
+/// resolved against the local filesystem.
+fn is_remote_entry(entry: &str) -> bool {
+    entry.starts_with("http:") || entry.starts_with("https:") || entry.starts_with("file:")
+}
+
+/// Makes a relative include/exclude entry absolute against `base_dir`,
+/// leaving remote (`http:`/`https:`/`file:`) entries untouched.
+fn make_absolute(entry: &str, base_dir: &Path) -> String {
+    if is_remote_entry(entry) || Path::new(entry).is_absolute() {
+        return entry.to_string();
+    }
+
+    base_dir.join(entry).display().to_string()
+}
+
+/// Splits an absolute include entry into the longest path prefix that
+/// contains no glob metacharacters and a glob to match walked paths
+/// against: that prefix joined with the remaining glob suffix.
+///
+/// Returns `None` for the glob when the entry has no metacharacters
+/// at all (e.g. a plain directory like `"src"`), since there's nothing to
+/// filter on: every file `WalkDir` finds under `base` belongs in the result.
+fn split_glob_base(entry: &str) -> (PathBuf, Option<String>) {
+    let mut base = PathBuf::new();
+    let mut rest = Vec::new();
+    let mut found_glob = false;
+
+    for component in Path::new(entry).components() {
+        let part = component.as_os_str().to_string_lossy();
+
+        if !found_glob && !is_glob_meta(&part) {
+            base.push(component.as_os_str());
+        } else {
+            found_glob = true;
+            rest.push(part.into_owned());
+        }
+    }
+
+    if base.as_os_str().is_empty() {
+        base.push(".");
+    }
+
+    let glob_pattern = if rest.is_empty() {
+        None
+    } else {
+        Some(format!("{}/{}", base.display(), rest.join("/")))
+    };
+
+    (base, glob_pattern)
+}
+
+/// Returns true if `part` contains a glob metacharacter.
+fn is_glob_meta(part: &str) -> bool {
+    part.contains(['*', '?', '[', '{'])
+}
+
+/// Compiles a glob pattern, wrapping the error with the pattern itself
+/// for an actionable message.
+fn compile_glob(pattern: &str) -> Result<GlobPattern> {
+    GlobPattern::new(pattern).with_context(|| format!("Invalid glob pattern '{}'", pattern))
+}
+
+/// Returns true if `path` matches any of `patterns`.
+fn matches_any(path: &Path, patterns: &[GlobPattern]) -> bool {
+    patterns.iter().any(|p| p.matches_path(path))
+}
+
+/// The regex dialects a saved pattern can be authored in. BRE requires a
+/// backslash to make `( ) { } + ? |` special; ERE and PCRE make them
+/// special by default and require a backslash to make them literal.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Dialect {
+    Bre,
+    Ere,
+    Pcre,
+}
+
+const GROUPING_METACHARS: [char; 7] = ['(', ')', '{', '}', '+', '?', '|'];
+
+fn parse_syntax(syntax: &str) -> Result<Dialect> {
+    match syntax {
+        "bre" => Ok(Dialect::Bre),
+        "ere" => Ok(Dialect::Ere),
+        "pcre" => Ok(Dialect::Pcre),
+        other => bail!(
+            "Unknown pattern syntax '{}' (expected bre, ere, or pcre)",
+            other
+        ),
+    }
+}
+
+/// Translates `alternatives`, authored in `from_syntax`, into the dialect the
+/// given `engine` expects, and joins more than one into a `(a|b|c)`
+/// alternation after translating each. Returns the translated pattern and
+/// whether the engine needs an extra `-P` flag to understand it (only
+/// meaningful for `grep`, whose native BRE/ERE modes cannot express `\b`).
+fn translate_pattern(
+    alternatives: &[String],
+    from_syntax: &str,
+    engine: &str,
+) -> Result<(String, bool)> {
+    let from = parse_syntax(from_syntax)?;
+
+    // rg and ag are PCRE-like for our purposes: metacharacters are special
+    // unescaped, and word boundaries are spelled `\b` rather than `\<`/`\>`.
+    let mut target_modern = engine != "grep";
+    let mut needs_pcre = false;
+
+    if !target_modern && alternatives.iter().any(|alt| contains_escaped(alt, 'b')) {
+        // Native grep (BRE/ERE) cannot express `\b`; fall back to -P, which
+        // speaks the same modern dialect as rg/ag.
+        target_modern = true;
+        needs_pcre = true;
+    }
+
+    let translated: Vec<String> = alternatives
+        .iter()
+        .map(|alt| rewrite_pattern(alt, from, target_modern))
+        .collect();
+
+    let pattern = if translated.len() == 1 {
+        translated[0].clone()
+    } else {
+        format!("({})", translated.join("|"))
+    };
+
+    Ok((pattern, needs_pcre))
+}
+
+/// Returns true if `pattern` contains `\<target>` outside a character class.
+fn contains_escaped(pattern: &str, target: char) -> bool {
+    let mut in_class = false;
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_class {
+            if c == ']' {
+                in_class = false;
+            }
+            continue;
+        }
+
+        match c {
+            '[' => in_class = true,
+            '\\' if chars.next() == Some(target) => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Rewrites `pattern` from `from` into the dialect matching `target_modern`
+/// (modern = ERE/PCRE-style unescaped metacharacters; not modern = BRE).
+/// Character classes (`[...]`) are copied through verbatim.
+fn rewrite_pattern(pattern: &str, from: Dialect, target_modern: bool) -> String {
+    let from_modern = from != Dialect::Bre;
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    let mut in_class = false;
+
+    while let Some(c) = chars.next() {
+        if in_class {
+            out.push(c);
+            if c == ']' {
+                in_class = false;
+            }
+            continue;
+        }
+
+        if c == '[' {
+            in_class = true;
+            out.push(c);
+            continue;
+        }
+
+        if c == '\\' {
+            match chars.next() {
+                Some('b') => out.push_str("\\b"),
+                Some(boundary @ ('<' | '>')) => {
+                    if target_modern {
+                        out.push_str("\\b");
+                    } else {
+                        out.push('\\');
+                        out.push(boundary);
+                    }
+                }
+                Some(m) if GROUPING_METACHARS.contains(&m) => {
+                    // Escaped metachar is special only when authored in BRE.
+                    emit_metachar(&mut out, m, !from_modern, target_modern);
+                }
+                Some(other) => {
+                    out.push('\\');
+                    out.push(other);
+                }
+                None => out.push('\\'),
+            }
+            continue;
+        }
+
+        if GROUPING_METACHARS.contains(&c) {
+            // Unescaped metachar is special unless authored in BRE.
+            emit_metachar(&mut out, c, from_modern, target_modern);
+            continue;
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+/// Emits `c`, adding a backslash exactly when its "specialness" in the
+/// source doesn't already match how the target dialect spells that meaning.
+fn emit_metachar(out: &mut String, c: char, is_special: bool, target_modern: bool) {
+    if is_special != target_modern {
+        out.push('\\');
+    }
+    out.push(c);
+}
+
+/// A pattern's alternatives and inherited settings after folding in
+/// everything reachable through its `includes` chain.
+pub struct ResolvedPattern {
+    pub alternatives: Vec<String>,
+    pub flags: Option<String>,
+    pub engine: Option<String>,
+    pub syntax: Option<String>,
+}
+
+/// Opens and parses the pattern file for `name`.
+fn load_pattern_file(pattern_dir: &Path, name: &str) -> Result<(Pattern, PathBuf)> {
+    let filename = pattern_dir.join(format!("{}.json", name));
+
+    let f = fs::File::open(&filename).with_context(|| format!("No such pattern '{}'", name))?;
+
+    let pat: Pattern = serde_json::from_reader(f)
+        .with_context(|| format!("Pattern file '{}' is malformed", filename.display()))?;
+
+    Ok((pat, filename))
+}
+
+/// Recursively resolves `name`, folding the alternatives of every pattern
+/// listed in its (and its includes') `includes` field into one list, and
+/// inheriting `flags`/`engine`/`syntax` from an included pattern wherever
+/// this one leaves them unset. `in_progress` tracks the names currently
+/// being expanded on this call stack so a cycle can be reported with the
+/// full chain instead of overflowing the stack.
+pub fn resolve_pattern(
+    pattern_dir: &Path,
+    name: &str,
+    in_progress: &mut Vec<String>,
+) -> Result<ResolvedPattern> {
+    if let Some(start) = in_progress.iter().position(|n| n == name) {
+        let mut chain = in_progress[start..].to_vec();
+        chain.push(name.to_string());
+        bail!(
+            "Cycle detected while resolving pattern includes: {}",
+            chain.join(" -> ")
+        );
+    }
+
+    let (pat, filename) = load_pattern_file(pattern_dir, name)?;
+
+    in_progress.push(name.to_string());
+
+    let mut alternatives = Vec::new();
+
+    if let Some(pattern) = &pat.pattern {
+        alternatives.push(pattern.clone());
+    }
+
+    if let Some(patterns) = &pat.patterns {
+        alternatives.extend(patterns.iter().cloned());
+    }
+
+    let mut flags = pat.flags.clone();
+    let mut engine = pat.engine.clone();
+    let mut syntax = pat.syntax.clone();
+
+    for included in pat.includes.iter().flatten() {
+        let resolved = resolve_pattern(pattern_dir, included, in_progress)?;
+        alternatives.extend(resolved.alternatives);
+        flags = flags.or(resolved.flags);
+        engine = engine.or(resolved.engine);
+        syntax = syntax.or(resolved.syntax);
+    }
+
+    in_progress.pop();
+
+    if alternatives.is_empty() {
+        bail!(
+            "Pattern file '{}' contains no pattern(s)",
+            filename.display()
+        );
+    }
+
+    Ok(ResolvedPattern {
+        alternatives,
+        flags,
+        engine,
+        syntax,
+    })
+}
+
+/// Determines the pattern directory in the user's home directory.
+fn get_pattern_dir() -> Result<PathBuf> {
+    let home_dir = dirs::home_dir().ok_or_else(|| anyhow!("Could not determine home directory"))?;
+
+    let config_dir = home_dir.join(".config/gf");
+
+    if config_dir.exists() {
+        return Ok(config_dir);
+    }
+
+    let gf_dir = home_dir.join(".gf");
+
+    Ok(gf_dir)
+}
+
+/// Saves a new pattern to the pattern directory.
+pub fn save_pattern(
+    name: &str,
+    flags: Option<&str>,
+    pattern: Option<&str>,
+    patterns: Option<Vec<String>>,
+    engine: Option<String>,
+) -> Result<()> {
+    if name.is_empty() {
+        bail!("Name cannot be empty");
+    }
+
+    let pattern = pattern.filter(|p| !p.is_empty()).map(str::to_string);
+    let patterns = patterns.filter(|p| !p.is_empty());
+
+    if pattern.is_none() && patterns.is_none() {
+        bail!("Pattern cannot be empty");
+    }
+
+    let p = Pattern {
+        flags: flags.filter(|f| !f.is_empty()).map(str::to_string),
+        pattern,
+        patterns,
+        engine,
+        include: None,
+        exclude: None,
+        syntax: None,
+        includes: None,
+    };
+
+    let pattern_dir = get_pattern_dir().context("Failed to determine pattern directory")?;
+
+    fs::create_dir_all(&pattern_dir).context("Failed to create pattern directory")?;
+
+    let path = pattern_dir.join(format!("{}.json", name));
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true).mode(0o666);
+
+    let f = options.open(&path).with_context(|| {
+        format!(
+            "Failed to create pattern file '{}': file may already exist",
+            path.display()
+        )
+    })?;
+
+    serde_json::to_writer_pretty(f, &p).context("Failed to write pattern file")?;
+
+    Ok(())
+}
+
+/// Opens a saved pattern's JSON file in the user's editor, creating a
+/// skeleton `Pattern` first if it doesn't exist yet.
+fn edit_pattern(name: &str) -> Result<()> {
+    if name.is_empty() {
+        bail!("Name cannot be empty");
+    }
+
+    let pattern_dir = get_pattern_dir().context("Failed to determine pattern directory")?;
+    fs::create_dir_all(&pattern_dir).context("Failed to create pattern directory")?;
+
+    let path = pattern_dir.join(format!("{}.json", name));
+
+    if !path.exists() {
+        let skeleton = Pattern {
+            flags: None,
+            pattern: Some(String::new()),
+            patterns: None,
+            engine: None,
+            include: None,
+            exclude: None,
+            syntax: None,
+            includes: None,
+        };
+
+        let f = fs::File::create(&path)
+            .with_context(|| format!("Failed to create pattern file '{}'", path.display()))?;
+
+        serde_json::to_writer_pretty(f, &skeleton).context("Failed to write pattern file")?;
+    }
+
+    let editor = editor_command();
+
+    let status = ProcessCommand::new(&editor)
+        .arg(&path)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .with_context(|| format!("Failed to launch editor '{}'", editor))?;
+
+    if !status.success() {
+        bail!("Editor '{}' exited with a non-zero status", editor);
+    }
+
+    Ok(())
+}
+
+/// Picks the editor to use, following the same precedence as git:
+/// `$VISUAL`, then `$EDITOR`, then `vim`.
+fn editor_command() -> String {
+    env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| "vim".to_string())
+}
+
+/// Retrieves a list of saved pattern names.
+pub fn list_patterns() -> Result<Vec<String>> {
+    let mut out = Vec::new();
+
+    let pattern_dir = get_pattern_dir().context("Failed to determine pattern directory")?;
+
+    if !pattern_dir.exists() {
+        // If the pattern directory doesn't exist, return an empty list
+        return Ok(out);
+    }
+
+    let entries = fs::read_dir(&pattern_dir).context("Failed to read pattern directory")?;
+
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            if let Some(filename) = path.file_stem().and_then(|s| s.to_str()) {
+                out.push(filename.to_string());
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// Checks if stdin is a pipe.
+fn stdin_is_pipe() -> bool {
+    !atty::is(Stream::Stdin)
+}
@@ -9,7 +9,7 @@ fn test_list_patterns_empty() -> Result<(), Box<dyn std::error::Error>> {
     // Test listing patterns when no patterns exist
     let temp_dir = tempdir()?;
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path()).arg("--list");
+    cmd.env("HOME", temp_dir.path()).arg("list");
     cmd.assert().success().stdout(predicate::str::is_empty());
     Ok(())
 }
@@ -22,12 +22,17 @@ fn test_save_pattern_and_list() -> Result<(), Box<dyn std::error::Error>> {
     fs::create_dir_all(&gf_dir)?;
 
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path())
-        .args(["--save", "testpattern", "-Hnri", "search-pattern"]);
+    cmd.env("HOME", temp_dir.path()).args([
+        "save",
+        "testpattern",
+        "search-pattern",
+        "--flags",
+        "-Hnri",
+    ]);
     cmd.assert().success();
 
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path()).arg("--list");
+    cmd.env("HOME", temp_dir.path()).arg("list");
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("testpattern"));
@@ -37,10 +42,11 @@ fn test_save_pattern_and_list() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_save_pattern_without_name() -> Result<(), Box<dyn std::error::Error>> {
-    // Test saving a pattern without providing a name
+    // Test saving a pattern with an empty name
     let temp_dir = tempdir()?;
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path()).arg("--save");
+    cmd.env("HOME", temp_dir.path())
+        .args(["save", "", "search-pattern"]);
     cmd.assert()
         .failure()
         .stderr(predicate::str::contains("Name cannot be empty"));
@@ -53,7 +59,7 @@ fn test_save_pattern_without_pattern() -> Result<(), Box<dyn std::error::Error>>
     let temp_dir = tempdir()?;
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--save", "test pattern"]);
+        .args(["save", "testpattern"]);
     cmd.assert()
         .failure()
         .stderr(predicate::str::contains("Pattern cannot be empty"));
@@ -62,7 +68,7 @@ fn test_save_pattern_without_pattern() -> Result<(), Box<dyn std::error::Error>>
 
 #[test]
 fn test_use_nonexistent_pattern() -> Result<(), Box<dyn std::error::Error>> {
-    // Test using a pattern that doesn't exist
+    // Test using a pattern that doesn't exist (no "run" keyword needed)
     let temp_dir = tempdir()?;
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path()).arg("nonexistentpattern");
@@ -82,19 +88,20 @@ fn test_dump_pattern() -> Result<(), Box<dyn std::error::Error>> {
     // Save a pattern with an engine and flags
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path()).args([
-        "--save",
+        "save",
         "testpattern",
+        "search-pattern",
         "--engine",
         "rg",
+        "--flags",
         "-Hnri",
-        "search-pattern",
     ]);
     cmd.assert().success();
 
     // Dump the pattern
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--dump", "testpattern", "/path/to/files"]);
+        .args(["dump", "testpattern", "/path/to/files"]);
     cmd.assert().success().stdout(predicate::str::contains(
         "rg -Hnri \"search-pattern\" /path/to/files",
     ));
@@ -111,7 +118,7 @@ fn test_execute_pattern_with_piped_input() -> Result<(), Box<dyn std::error::Err
     // Save a simple pattern
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--save", "testpattern", "-nri", "test"]);
+        .args(["save", "testpattern", "test", "--flags", "-nri"]);
     cmd.assert().success();
 
     // Create a temporary file to grep
@@ -121,7 +128,7 @@ fn test_execute_pattern_with_piped_input() -> Result<(), Box<dyn std::error::Err
     writeln!(temp_file, "Another line")?;
     drop(temp_file);
 
-    // Use the pattern with piped input
+    // Use the pattern with piped input (no "run" keyword needed)
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
         .arg("testpattern")
@@ -185,14 +192,24 @@ fn test_save_pattern_with_existing_name() -> Result<(), Box<dyn std::error::Erro
 
     // Save the initial pattern
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path())
-        .args(["--save", "testpattern", "-Hnri", "search-pattern"]);
+    cmd.env("HOME", temp_dir.path()).args([
+        "save",
+        "testpattern",
+        "search-pattern",
+        "--flags",
+        "-Hnri",
+    ]);
     cmd.assert().success();
 
     // Attempt to save another pattern with the same name
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path())
-        .args(["--save", "testpattern", "-Hnri", "another-pattern"]);
+    cmd.env("HOME", temp_dir.path()).args([
+        "save",
+        "testpattern",
+        "another-pattern",
+        "--flags",
+        "-Hnri",
+    ]);
     cmd.assert()
         .failure()
         .stderr(predicate::str::contains("Failed to create pattern file"));
@@ -207,22 +224,213 @@ fn test_dump_pattern_with_no_flags() -> Result<(), Box<dyn std::error::Error>> {
     let gf_dir = temp_dir.path().join(".config/gf");
     fs::create_dir_all(&gf_dir)?;
 
-    // Save a pattern without flags by providing an empty string for flags
+    // Save a pattern without flags
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--save", "noflagpattern", "", "search-pattern"]);
+        .args(["save", "noflagpattern", "search-pattern"]);
     cmd.assert().success();
 
     // Dump the pattern
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--dump", "noflagpattern", "/path/to/files"]);
+        .args(["dump", "noflagpattern", "/path/to/files"]);
     cmd.assert().success().stdout(predicate::str::contains(
         "grep \"search-pattern\" /path/to/files",
     ));
     Ok(())
 }
 
+#[test]
+fn test_edit_creates_skeleton_and_invokes_editor() -> Result<(), Box<dyn std::error::Error>> {
+    // Test that editing a nonexistent pattern creates a skeleton file and
+    // launches the configured editor against it
+    let temp_dir = tempdir()?;
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .env("EDITOR", "true")
+        .args(["edit", "newpattern"]);
+    cmd.assert().success();
+
+    let pattern_path = temp_dir
+        .path()
+        .join(".gf")
+        .join("newpattern.json");
+    let contents = fs::read_to_string(&pattern_path)?;
+    assert!(contents.contains("\"pattern\": \"\""));
+
+    Ok(())
+}
+
+#[test]
+fn test_edit_prefers_visual_over_editor() -> Result<(), Box<dyn std::error::Error>> {
+    // $VISUAL should win over $EDITOR, matching git's precedence
+    let temp_dir = tempdir()?;
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .env("VISUAL", "true")
+        .env("EDITOR", "false")
+        .args(["edit", "anotherpattern"]);
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn test_run_with_include_walks_nested_directories_and_excludes(
+) -> Result<(), Box<dyn std::error::Error>> {
+    // A glob-less include (e.g. "src") should recurse into every file
+    // beneath it, while a matching exclude glob should still drop files
+    let temp_dir = tempdir()?;
+    let gf_dir = temp_dir.path().join(".config/gf");
+    fs::create_dir_all(&gf_dir)?;
+
+    let src_dir = temp_dir.path().join("src");
+    fs::create_dir_all(&src_dir)?;
+    File::create(src_dir.join("a.rs"))?;
+    File::create(src_dir.join("b.rs"))?;
+
+    let nested_dir = src_dir.join("generated");
+    fs::create_dir_all(&nested_dir)?;
+    File::create(nested_dir.join("c.rs"))?;
+
+    let pattern_file_path = gf_dir.join("walkpattern.json");
+    let mut pattern_file = File::create(&pattern_file_path)?;
+    writeln!(
+        pattern_file,
+        r#"{{ "pattern": "foo", "include": ["src"], "exclude": ["src/generated/**"] }}"#
+    )?;
+    drop(pattern_file);
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .args(["dump", "walkpattern"]);
+    cmd.assert()
+        .success()
+        .stdout(
+            predicate::str::contains("a.rs")
+                .and(predicate::str::contains("b.rs"))
+                .and(predicate::str::contains("c.rs").not()),
+        );
+
+    Ok(())
+}
+
+#[test]
+fn test_run_with_include_resolving_to_no_files_fails() -> Result<(), Box<dyn std::error::Error>> {
+    // An include list that resolves to zero concrete files must error out
+    // instead of silently handing the engine an empty file list
+    let temp_dir = tempdir()?;
+    let gf_dir = temp_dir.path().join(".config/gf");
+    fs::create_dir_all(&gf_dir)?;
+
+    let pattern_file_path = gf_dir.join("emptywalk.json");
+    let mut pattern_file = File::create(&pattern_file_path)?;
+    writeln!(
+        pattern_file,
+        r#"{{ "pattern": "foo", "include": ["nonexistent-dir/*.rs"] }}"#
+    )?;
+    drop(pattern_file);
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .current_dir(temp_dir.path())
+        .args(["dump", "emptywalk"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "resolved to zero files",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_dump_translates_bre_grouping_for_modern_engine(
+) -> Result<(), Box<dyn std::error::Error>> {
+    // A pattern authored in BRE (escaped parens are special) should come out
+    // unescaped when dumped for a PCRE-like engine such as rg
+    let temp_dir = tempdir()?;
+    let gf_dir = temp_dir.path().join(".config/gf");
+    fs::create_dir_all(&gf_dir)?;
+
+    let pattern_file_path = gf_dir.join("brepattern.json");
+    let mut pattern_file = File::create(&pattern_file_path)?;
+    writeln!(
+        pattern_file,
+        r#"{{ "pattern": "foo\\(bar\\)", "engine": "rg", "syntax": "bre" }}"#
+    )?;
+    drop(pattern_file);
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .args(["dump", "brepattern", "."]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"foo(bar)\""));
+
+    Ok(())
+}
+
+#[test]
+fn test_includes_folds_alternatives_from_other_patterns(
+) -> Result<(), Box<dyn std::error::Error>> {
+    // A pattern with no alternatives of its own but an `includes` chain
+    // should fold the included patterns' alternatives into one alternation
+    let temp_dir = tempdir()?;
+    let gf_dir = temp_dir.path().join(".config/gf");
+    fs::create_dir_all(&gf_dir)?;
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .args(["save", "base1", "foo"]);
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .args(["save", "base2", "bar"]);
+    cmd.assert().success();
+
+    let pattern_file_path = gf_dir.join("combo.json");
+    let mut pattern_file = File::create(&pattern_file_path)?;
+    writeln!(pattern_file, r#"{{ "includes": ["base1", "base2"] }}"#)?;
+    drop(pattern_file);
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .args(["dump", "combo", "."]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("(foo|bar)"));
+
+    Ok(())
+}
+
+#[test]
+fn test_includes_cycle_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    // A cycle in the includes graph must be reported, not overflow the stack
+    let temp_dir = tempdir()?;
+    let gf_dir = temp_dir.path().join(".config/gf");
+    fs::create_dir_all(&gf_dir)?;
+
+    let mut a = File::create(gf_dir.join("cyclea.json"))?;
+    writeln!(a, r#"{{ "includes": ["cycleb"] }}"#)?;
+    drop(a);
+
+    let mut b = File::create(gf_dir.join("cycleb.json"))?;
+    writeln!(b, r#"{{ "includes": ["cyclea"] }}"#)?;
+    drop(b);
+
+    let mut cmd = Command::cargo_bin("gf")?;
+    cmd.env("HOME", temp_dir.path())
+        .args(["dump", "cyclea", "."]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Cycle detected"));
+
+    Ok(())
+}
+
 #[test]
 fn test_list_patterns_with_multiple_patterns() -> Result<(), Box<dyn std::error::Error>> {
     // Test listing when multiple patterns exist
@@ -233,16 +441,16 @@ fn test_list_patterns_with_multiple_patterns() -> Result<(), Box<dyn std::error:
     // Save multiple patterns
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--save", "pattern1", "-nri", "test1"]);
+        .args(["save", "pattern1", "test1", "--flags", "-nri"]);
     cmd.assert().success();
 
     let mut cmd = Command::cargo_bin("gf")?;
     cmd.env("HOME", temp_dir.path())
-        .args(["--save", "pattern2", "-nri", "test2"]);
+        .args(["save", "pattern2", "test2", "--flags", "-nri"]);
     cmd.assert().success();
 
     let mut cmd = Command::cargo_bin("gf")?;
-    cmd.env("HOME", temp_dir.path()).arg("--list");
+    cmd.env("HOME", temp_dir.path()).arg("list");
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("pattern1").and(predicate::str::contains("pattern2")));